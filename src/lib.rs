@@ -18,6 +18,16 @@
 //! let suffixed = outerspace::suffix_non_whitespace("\n\nHello hello\n\n", "!");
 //! assert_eq!(suffixed, "\n\nHello hello!\n\n");
 //! ```
+//!
+//! ```
+//! let unwrapped = outerspace::unwrap_non_whitespace("\n\n**Hello hello**\n\n", "**", "**");
+//! assert_eq!(unwrapped, "\n\nHello hello\n\n");
+//! ```
+
+use std::borrow::Cow;
+use std::fmt;
+
+use unicode_width::UnicodeWidthChar;
 
 fn is_non_whitespace(char: char) -> bool {
     !char.is_whitespace()
@@ -50,6 +60,59 @@ fn format_wrap(
     }
 }
 
+/// Insert a prefix and a suffix into the string around the span delimited by the first and last
+/// characters matching `is_content`. The prefix is inserted before the first matching character.
+/// The suffix is inserted after the last matching character.
+///
+/// Returns a heap-allocated String.
+///
+/// # Example
+///
+/// ```
+/// let wrapped = outerspace::wrap_matching("\n\nHello hello\n\n", "**", "**", |c| !c.is_whitespace());
+/// assert_eq!(wrapped, "\n\n**Hello hello**\n\n");
+/// ```
+pub fn wrap_matching<F: Fn(char) -> bool>(
+    s: &str,
+    prefix: &str,
+    suffix: &str,
+    is_content: F,
+) -> String {
+    let first_content = s.find(&is_content);
+    let last_content = s.rfind(&is_content);
+    format_wrap(s, prefix, suffix, first_content, last_content)
+}
+
+/// Insert a prefix into the string before the first character matching `is_content`.
+///
+/// Returns a heap-allocated String.
+///
+/// # Example
+///
+/// ```
+/// let prefixed = outerspace::prefix_matching("\n\nHello hello\n\n", "> ", |c| !c.is_whitespace());
+/// assert_eq!(prefixed, "\n\n> Hello hello\n\n");
+/// ```
+pub fn prefix_matching<F: Fn(char) -> bool>(s: &str, prefix: &str, is_content: F) -> String {
+    let first_content = s.find(&is_content);
+    format_wrap(s, prefix, "", first_content, None)
+}
+
+/// Insert a suffix into the string after the last character matching `is_content`.
+///
+/// Returns a heap-allocated String.
+///
+/// # Example
+///
+/// ```
+/// let suffixed = outerspace::suffix_matching("\n\nHello hello\n\n", "!", |c| !c.is_whitespace());
+/// assert_eq!(suffixed, "\n\nHello hello!\n\n");
+/// ```
+pub fn suffix_matching<F: Fn(char) -> bool>(s: &str, suffix: &str, is_content: F) -> String {
+    let last_content = s.rfind(&is_content);
+    format_wrap(s, "", suffix, None, last_content)
+}
+
 /// Insert a prefix and a suffix into the string. The prefix is inserted before the first non-whitespace character. The suffix is inserted after the last non-whitespace character.
 ///
 /// Returns a heap-allocated String.
@@ -61,9 +124,7 @@ fn format_wrap(
 /// assert_eq!(wrapped, "\n\n**Hello hello**\n\n");
 /// ```
 pub fn wrap_non_whitespace(s: &str, prefix: &str, suffix: &str) -> String {
-    let first_non_whitespace = s.find(is_non_whitespace);
-    let last_non_whitespace = s.rfind(is_non_whitespace);
-    format_wrap(s, prefix, suffix, first_non_whitespace, last_non_whitespace)
+    wrap_matching(s, prefix, suffix, is_non_whitespace)
 }
 
 /// Insert a prefix into the string before the first non-whitespace character.
@@ -77,8 +138,7 @@ pub fn wrap_non_whitespace(s: &str, prefix: &str, suffix: &str) -> String {
 /// assert_eq!(prefixed, "\n\n> Hello hello\n\n");
 /// ```
 pub fn prefix_non_whitespace(s: &str, prefix: &str) -> String {
-    let first_non_whitespace = s.find(is_non_whitespace);
-    format_wrap(s, prefix, "", first_non_whitespace, None)
+    prefix_matching(s, prefix, is_non_whitespace)
 }
 
 /// Insert a suffix into the string after the last non-whitespace character.
@@ -92,14 +152,445 @@ pub fn prefix_non_whitespace(s: &str, prefix: &str) -> String {
 /// assert_eq!(suffixed, "\n\nHello hello!\n\n");
 /// ```
 pub fn suffix_non_whitespace(s: &str, suffix: &str) -> String {
+    suffix_matching(s, suffix, is_non_whitespace)
+}
+
+/// Insert a prefix and a suffix into the string, like [`wrap_non_whitespace`], but borrow the
+/// input instead of allocating when both `prefix` and `suffix` are empty.
+///
+/// # Example
+///
+/// ```
+/// let wrapped = outerspace::wrap_non_whitespace_cow("\n\nHello hello\n\n", "**", "**");
+/// assert_eq!(wrapped, "\n\n**Hello hello**\n\n");
+/// ```
+pub fn wrap_non_whitespace_cow<'a>(s: &'a str, prefix: &str, suffix: &str) -> Cow<'a, str> {
+    if prefix.is_empty() && suffix.is_empty() {
+        Cow::Borrowed(s)
+    } else {
+        Cow::Owned(wrap_non_whitespace(s, prefix, suffix))
+    }
+}
+
+/// Insert a prefix into the string, like [`prefix_non_whitespace`], but borrow the input instead
+/// of allocating when `prefix` is empty.
+///
+/// # Example
+///
+/// ```
+/// let prefixed = outerspace::prefix_non_whitespace_cow("\n\nHello hello\n\n", "> ");
+/// assert_eq!(prefixed, "\n\n> Hello hello\n\n");
+/// ```
+pub fn prefix_non_whitespace_cow<'a>(s: &'a str, prefix: &str) -> Cow<'a, str> {
+    if prefix.is_empty() {
+        Cow::Borrowed(s)
+    } else {
+        Cow::Owned(prefix_non_whitespace(s, prefix))
+    }
+}
+
+/// Insert a suffix into the string, like [`suffix_non_whitespace`], but borrow the input instead
+/// of allocating when `suffix` is empty.
+///
+/// # Example
+///
+/// ```
+/// let suffixed = outerspace::suffix_non_whitespace_cow("\n\nHello hello\n\n", "!");
+/// assert_eq!(suffixed, "\n\nHello hello!\n\n");
+/// ```
+pub fn suffix_non_whitespace_cow<'a>(s: &'a str, suffix: &str) -> Cow<'a, str> {
+    if suffix.is_empty() {
+        Cow::Borrowed(s)
+    } else {
+        Cow::Owned(suffix_non_whitespace(s, suffix))
+    }
+}
+
+fn format_wrap_into<W: fmt::Write>(
+    w: &mut W,
+    s: &str,
+    prefix: &str,
+    suffix: &str,
+    first_non_whitespace: Option<usize>,
+    last_non_whitespace: Option<usize>,
+) -> fmt::Result {
+    match (first_non_whitespace, last_non_whitespace) {
+        (Some(start), Some(end)) => {
+            let (leading_ws, rest) = s.split_at(start);
+            let (rest, trailing_ws) = rest.split_at(end - start + 1);
+            w.write_str(leading_ws)?;
+            w.write_str(prefix)?;
+            w.write_str(rest)?;
+            w.write_str(suffix)?;
+            w.write_str(trailing_ws)
+        }
+        (Some(start), None) => {
+            let (leading_ws, rest) = s.split_at(start);
+            w.write_str(leading_ws)?;
+            w.write_str(prefix)?;
+            w.write_str(rest)?;
+            w.write_str(suffix)
+        }
+        (None, Some(end)) => {
+            let (rest, trailing_ws) = s.split_at(end + 1);
+            w.write_str(prefix)?;
+            w.write_str(rest)?;
+            w.write_str(suffix)?;
+            w.write_str(trailing_ws)
+        }
+        (None, None) => {
+            w.write_str(prefix)?;
+            w.write_str(s)?;
+            w.write_str(suffix)
+        }
+    }
+}
+
+/// Insert a prefix and a suffix into the string, like [`wrap_non_whitespace`], but stream the
+/// result straight into `w` instead of building an intermediate `String`. Useful for callers
+/// assembling a large document line-by-line who want to avoid an allocation per call.
+///
+/// # Example
+///
+/// ```
+/// use std::fmt::Write;
+///
+/// let mut buf = String::new();
+/// outerspace::wrap_non_whitespace_into(&mut buf, "\n\nHello hello\n\n", "**", "**").unwrap();
+/// assert_eq!(buf, "\n\n**Hello hello**\n\n");
+/// ```
+pub fn wrap_non_whitespace_into<W: fmt::Write>(
+    w: &mut W,
+    s: &str,
+    prefix: &str,
+    suffix: &str,
+) -> fmt::Result {
+    let first_non_whitespace = s.find(is_non_whitespace);
+    let last_non_whitespace = s.rfind(is_non_whitespace);
+    format_wrap_into(
+        w,
+        s,
+        prefix,
+        suffix,
+        first_non_whitespace,
+        last_non_whitespace,
+    )
+}
+
+fn format_wrap_lines(s: &str, prefix: &str, suffix: &str) -> String {
+    s.split_inclusive('\n')
+        .map(|line| {
+            let first_non_whitespace = line.find(is_non_whitespace);
+            let last_non_whitespace = line.rfind(is_non_whitespace);
+            match (first_non_whitespace, last_non_whitespace) {
+                (Some(_), Some(_)) => format_wrap(
+                    line,
+                    prefix,
+                    suffix,
+                    first_non_whitespace,
+                    last_non_whitespace,
+                ),
+                _ => line.to_string(),
+            }
+        })
+        .collect()
+}
+
+/// Insert a prefix and a suffix into each line of the string. Within each line, the prefix is
+/// inserted before the first non-whitespace character and the suffix is inserted after the last
+/// non-whitespace character. A line that is empty or consists solely of whitespace is left
+/// untouched, so blank-line gaps survive unchanged.
+///
+/// Returns a heap-allocated String.
+///
+/// # Example
+///
+/// ```
+/// let wrapped = outerspace::wrap_non_whitespace_lines("cosy\n\nmatthew\n", "<", ">");
+/// assert_eq!(wrapped, "<cosy>\n\n<matthew>\n");
+/// ```
+pub fn wrap_non_whitespace_lines(s: &str, prefix: &str, suffix: &str) -> String {
+    format_wrap_lines(s, prefix, suffix)
+}
+
+/// Insert a prefix into each line of the string, before the first non-whitespace character of
+/// each line. A line that is empty or consists solely of whitespace is left untouched, so
+/// blank-line gaps survive unchanged.
+///
+/// Returns a heap-allocated String.
+///
+/// # Example
+///
+/// ```
+/// let prefixed = outerspace::prefix_non_whitespace_lines("cosy\n\nmatthew\n", "> ");
+/// assert_eq!(prefixed, "> cosy\n\n> matthew\n");
+/// ```
+pub fn prefix_non_whitespace_lines(s: &str, prefix: &str) -> String {
+    format_wrap_lines(s, prefix, "")
+}
+
+/// Insert a suffix into each line of the string, after the last non-whitespace character of each
+/// line. A line that is empty or consists solely of whitespace is left untouched, so blank-line
+/// gaps survive unchanged.
+///
+/// Returns a heap-allocated String.
+///
+/// # Example
+///
+/// ```
+/// let suffixed = outerspace::suffix_non_whitespace_lines("cosy\n\nmatthew\n", "!");
+/// assert_eq!(suffixed, "cosy!\n\nmatthew!\n");
+/// ```
+pub fn suffix_non_whitespace_lines(s: &str, suffix: &str) -> String {
+    format_wrap_lines(s, "", suffix)
+}
+
+/// Which end of the content span to keep when it has to be shortened to fit `max_width`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TruncateFrom {
+    /// Keep the head of the content, dropping characters from the end.
+    End,
+    /// Keep the tail of the content, dropping characters from the start. Useful for long paths.
+    Start,
+}
+
+fn display_width(s: &str) -> usize {
+    s.chars()
+        .map(|char| UnicodeWidthChar::width(char).unwrap_or(0))
+        .sum()
+}
+
+fn truncate_to_width(s: &str, max_width: usize) -> &str {
+    let mut width = 0;
+    let mut end = 0;
+    for (index, char) in s.char_indices() {
+        let char_width = UnicodeWidthChar::width(char).unwrap_or(0);
+        if width + char_width > max_width {
+            break;
+        }
+        width += char_width;
+        end = index + char.len_utf8();
+    }
+    &s[..end]
+}
+
+fn truncate_to_width_from_end(s: &str, max_width: usize) -> &str {
+    let mut width = 0;
+    let mut start = s.len();
+    for (index, char) in s.char_indices().rev() {
+        let char_width = UnicodeWidthChar::width(char).unwrap_or(0);
+        if width + char_width > max_width {
+            break;
+        }
+        width += char_width;
+        start = index;
+    }
+    &s[start..]
+}
+
+fn truncate_content<'a>(
+    content: &'a str,
+    max_width: usize,
+    ellipsis: &str,
+    from: TruncateFrom,
+) -> Cow<'a, str> {
+    if display_width(content) <= max_width {
+        return Cow::Borrowed(content);
+    }
+    let ellipsis_width = display_width(ellipsis);
+    if max_width <= ellipsis_width {
+        return Cow::Owned(truncate_to_width(ellipsis, max_width).to_string());
+    }
+    let budget = max_width - ellipsis_width;
+    match from {
+        TruncateFrom::End => Cow::Owned(format!(
+            "{}{}",
+            truncate_to_width(content, budget),
+            ellipsis
+        )),
+        TruncateFrom::Start => Cow::Owned(format!(
+            "{}{}",
+            ellipsis,
+            truncate_to_width_from_end(content, budget)
+        )),
+    }
+}
+
+/// Insert a prefix and a suffix into the string, first truncating the non-whitespace content to
+/// fit within `max_width` terminal columns, inserting `ellipsis` where it had to cut. Display
+/// width is computed with [`unicode_width`], so wide characters (e.g. CJK) count as two columns
+/// and zero-width/combining marks count as zero. `from` chooses whether the head or the tail of
+/// the content is kept. Leading and trailing whitespace around the content is left untouched.
+///
+/// If `max_width` is smaller than the display width of `ellipsis`, the content is replaced by
+/// `ellipsis` itself truncated to fit `max_width`; the prefix, suffix and surrounding whitespace
+/// are still kept.
+///
+/// Returns a heap-allocated String.
+///
+/// # Example
+///
+/// ```
+/// use outerspace::TruncateFrom;
+/// let wrapped =
+///     outerspace::wrap_non_whitespace_truncated("Hello hello", "**", "**", 5, "…", TruncateFrom::End);
+/// assert_eq!(wrapped, "**Hell…**");
+///
+/// let wrapped = outerspace::wrap_non_whitespace_truncated(
+///     "Hello hello",
+///     "**",
+///     "**",
+///     5,
+///     "…",
+///     TruncateFrom::Start,
+/// );
+/// assert_eq!(wrapped, "**…ello**");
+/// ```
+pub fn wrap_non_whitespace_truncated(
+    s: &str,
+    prefix: &str,
+    suffix: &str,
+    max_width: usize,
+    ellipsis: &str,
+    from: TruncateFrom,
+) -> String {
+    let first_non_whitespace = s.find(is_non_whitespace);
+    let last_non_whitespace = s.rfind(is_non_whitespace);
+    match (first_non_whitespace, last_non_whitespace) {
+        (Some(start), Some(end)) => {
+            // `end` is the byte index where the last non-whitespace char *starts*; widen it to
+            // that char's full length so multi-byte chars aren't cut in half.
+            let content_end = end + s[end..].chars().next().map_or(0, char::len_utf8);
+            let content = &s[start..content_end];
+            let truncated = truncate_content(content, max_width, ellipsis, from);
+            format!(
+                "{}{}{}{}{}",
+                &s[..start],
+                prefix,
+                truncated,
+                suffix,
+                &s[content_end..]
+            )
+        }
+        _ => format_wrap(s, prefix, suffix, first_non_whitespace, last_non_whitespace),
+    }
+}
+
+fn format_unwrap<'a>(
+    s: &'a str,
+    prefix: &str,
+    suffix: &str,
+    first_non_whitespace: Option<usize>,
+    last_non_whitespace: Option<usize>,
+) -> Cow<'a, str> {
+    match (first_non_whitespace, last_non_whitespace) {
+        (Some(start), Some(end)) => {
+            let content = &s[start..=end];
+            let stripped = content.strip_prefix(prefix).unwrap_or(content);
+            let stripped = stripped.strip_suffix(suffix).unwrap_or(stripped);
+            if stripped.len() == content.len() {
+                Cow::Borrowed(s)
+            } else {
+                Cow::Owned(format!("{}{}{}", &s[..start], stripped, &s[end + 1..]))
+            }
+        }
+        _ => Cow::Borrowed(s),
+    }
+}
+
+/// Remove a prefix and a suffix surrounding the non-whitespace content of the string, undoing
+/// what [`wrap_non_whitespace`] produces. If the content doesn't begin with `prefix` or doesn't
+/// end with `suffix`, that marker is left in place. Leading and trailing whitespace is preserved.
+///
+/// Returns the original string borrowed when nothing was removed, and a newly allocated String
+/// otherwise, so callers can tell whether a marker was stripped.
+///
+/// # Example
+///
+/// ```
+/// let unwrapped = outerspace::unwrap_non_whitespace("\n\n**Hello hello**\n\n", "**", "**");
+/// assert_eq!(unwrapped, "\n\nHello hello\n\n");
+/// ```
+pub fn unwrap_non_whitespace<'a>(s: &'a str, prefix: &str, suffix: &str) -> Cow<'a, str> {
+    let first_non_whitespace = s.find(is_non_whitespace);
+    let last_non_whitespace = s.rfind(is_non_whitespace);
+    format_unwrap(s, prefix, suffix, first_non_whitespace, last_non_whitespace)
+}
+
+/// Remove a prefix from before the first non-whitespace character of the string, undoing what
+/// [`prefix_non_whitespace`] produces. If the content doesn't begin with `prefix`, the string is
+/// returned unchanged.
+///
+/// Returns the original string borrowed when nothing was removed, and a newly allocated String
+/// otherwise, so callers can tell whether the prefix was stripped.
+///
+/// # Example
+///
+/// ```
+/// let unprefixed = outerspace::unprefix_non_whitespace("\n\n> Hello hello\n\n", "> ");
+/// assert_eq!(unprefixed, "\n\nHello hello\n\n");
+/// ```
+pub fn unprefix_non_whitespace<'a>(s: &'a str, prefix: &str) -> Cow<'a, str> {
+    let first_non_whitespace = s.find(is_non_whitespace);
+    let last_non_whitespace = s.rfind(is_non_whitespace);
+    format_unwrap(s, prefix, "", first_non_whitespace, last_non_whitespace)
+}
+
+/// Remove a suffix from after the last non-whitespace character of the string, undoing what
+/// [`suffix_non_whitespace`] produces. If the content doesn't end with `suffix`, the string is
+/// returned unchanged.
+///
+/// Returns the original string borrowed when nothing was removed, and a newly allocated String
+/// otherwise, so callers can tell whether the suffix was stripped.
+///
+/// # Example
+///
+/// ```
+/// let unsuffixed = outerspace::unsuffix_non_whitespace("\n\nHello hello!\n\n", "!");
+/// assert_eq!(unsuffixed, "\n\nHello hello\n\n");
+/// ```
+pub fn unsuffix_non_whitespace<'a>(s: &'a str, suffix: &str) -> Cow<'a, str> {
+    let first_non_whitespace = s.find(is_non_whitespace);
     let last_non_whitespace = s.rfind(is_non_whitespace);
-    format_wrap(s, "", suffix, None, last_non_whitespace)
+    format_unwrap(s, "", suffix, first_non_whitespace, last_non_whitespace)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn wrap_matching_works() {
+        assert_eq!(
+            wrap_matching("42cosy7", "<", ">", |c| c.is_alphabetic()),
+            "42<cosy>7"
+        );
+        assert_eq!(
+            wrap_matching("1,2,3", "[", "]", |c| c.is_ascii_digit()),
+            "[1,2,3]"
+        );
+        assert_eq!(
+            wrap_matching("...", "<", ">", |c| c.is_alphabetic()),
+            "<...>"
+        );
+    }
+
+    #[test]
+    fn prefix_matching_works() {
+        assert_eq!(
+            prefix_matching("42cosy", "<", |c| c.is_alphabetic()),
+            "42<cosy"
+        );
+    }
+
+    #[test]
+    fn suffix_matching_works() {
+        assert_eq!(
+            suffix_matching("cosy42", ">", |c| c.is_alphabetic()),
+            "cosy>42"
+        );
+    }
+
     #[test]
     fn wrap_works() {
         assert_eq!(wrap_non_whitespace("", "<", ">"), "<>");
@@ -144,4 +635,181 @@ mod tests {
             "emboldened \nmatthew**\n \n"
         );
     }
+
+    #[test]
+    fn wrap_lines_works() {
+        assert_eq!(wrap_non_whitespace_lines("", "<", ">"), "");
+        assert_eq!(wrap_non_whitespace_lines("  \n ", "<", ">"), "  \n ");
+        assert_eq!(wrap_non_whitespace_lines("cosy", "<", ">"), "<cosy>");
+        assert_eq!(
+            wrap_non_whitespace_lines("cosy matthew", "<", ">"),
+            "<cosy matthew>"
+        );
+        assert_eq!(
+            wrap_non_whitespace_lines("\n \ncosy \nmatthew\n \n", "<", ">"),
+            "\n \n<cosy> \n<matthew>\n \n"
+        );
+    }
+
+    #[test]
+    fn prefix_lines_works() {
+        assert_eq!(prefix_non_whitespace_lines("", "**"), "");
+        assert_eq!(prefix_non_whitespace_lines("  \n ", "**"), "  \n ");
+        assert_eq!(
+            prefix_non_whitespace_lines("emboldened", "**"),
+            "**emboldened"
+        );
+        assert_eq!(
+            prefix_non_whitespace_lines("\n \nemboldened \nmatthew", "**"),
+            "\n \n**emboldened \n**matthew"
+        );
+    }
+
+    #[test]
+    fn suffix_lines_works() {
+        assert_eq!(suffix_non_whitespace_lines("", "**"), "");
+        assert_eq!(suffix_non_whitespace_lines("  \n ", "**"), "  \n ");
+        assert_eq!(
+            suffix_non_whitespace_lines("emboldened", "**"),
+            "emboldened**"
+        );
+        assert_eq!(
+            suffix_non_whitespace_lines("emboldened \nmatthew\n \n", "**"),
+            "emboldened** \nmatthew**\n \n"
+        );
+    }
+
+    #[test]
+    fn wrap_truncated_works() {
+        // fits within max_width: unchanged
+        assert_eq!(
+            wrap_non_whitespace_truncated("cosy", "<", ">", 10, "…", TruncateFrom::End),
+            "<cosy>"
+        );
+        // truncated from the end, keeping the head
+        assert_eq!(
+            wrap_non_whitespace_truncated("Hello hello", "**", "**", 5, "…", TruncateFrom::End),
+            "**Hell…**"
+        );
+        // truncated from the start, keeping the tail
+        assert_eq!(
+            wrap_non_whitespace_truncated("Hello hello", "**", "**", 5, "…", TruncateFrom::Start),
+            "**…ello**"
+        );
+        // leading/trailing whitespace is preserved untouched
+        assert_eq!(
+            wrap_non_whitespace_truncated(
+                "\n \nHello hello\n \n",
+                "<",
+                ">",
+                5,
+                "…",
+                TruncateFrom::End
+            ),
+            "\n \n<Hell…>\n \n"
+        );
+        // wide (double-width) characters count as two columns
+        assert_eq!(
+            wrap_non_whitespace_truncated("こんにちは", "<", ">", 5, "…", TruncateFrom::End),
+            "<こん…>"
+        );
+        // max_width smaller than the ellipsis: just the ellipsis, truncated to fit
+        assert_eq!(
+            wrap_non_whitespace_truncated("Hello hello", "<", ">", 0, "…", TruncateFrom::End),
+            "<>"
+        );
+    }
+
+    #[test]
+    fn wrap_cow_works() {
+        assert!(matches!(
+            wrap_non_whitespace_cow("cosy matthew", "", ""),
+            Cow::Borrowed("cosy matthew")
+        ));
+        assert_eq!(
+            wrap_non_whitespace_cow("cosy matthew", "<", ">"),
+            "<cosy matthew>"
+        );
+    }
+
+    #[test]
+    fn prefix_cow_works() {
+        assert!(matches!(
+            prefix_non_whitespace_cow("cosy matthew", ""),
+            Cow::Borrowed("cosy matthew")
+        ));
+        assert_eq!(
+            prefix_non_whitespace_cow("cosy matthew", "**"),
+            "**cosy matthew"
+        );
+    }
+
+    #[test]
+    fn suffix_cow_works() {
+        assert!(matches!(
+            suffix_non_whitespace_cow("cosy matthew", ""),
+            Cow::Borrowed("cosy matthew")
+        ));
+        assert_eq!(
+            suffix_non_whitespace_cow("cosy matthew", "**"),
+            "cosy matthew**"
+        );
+    }
+
+    #[test]
+    fn wrap_into_works() {
+        let mut buf = String::new();
+        wrap_non_whitespace_into(&mut buf, "\n\nHello hello\n\n", "**", "**").unwrap();
+        assert_eq!(buf, "\n\n**Hello hello**\n\n");
+
+        let mut buf = String::new();
+        wrap_non_whitespace_into(&mut buf, "  \n ", "<", ">").unwrap();
+        assert_eq!(buf, "<  \n >");
+    }
+
+    #[test]
+    fn unwrap_works() {
+        assert_eq!(unwrap_non_whitespace("<>", "<", ">"), "");
+        assert_eq!(unwrap_non_whitespace("<  \n >", "<", ">"), "  \n ");
+        assert_eq!(unwrap_non_whitespace("<cosy>", "<", ">"), "cosy");
+        assert_eq!(
+            unwrap_non_whitespace("<cosy matthew>", "<", ">"),
+            "cosy matthew"
+        );
+        assert_eq!(
+            unwrap_non_whitespace("\n \n<cosy \nmatthew>\n \n", "<", ">"),
+            "\n \ncosy \nmatthew\n \n"
+        );
+        // markers absent: left untouched
+        assert_eq!(
+            unwrap_non_whitespace("cosy matthew", "<", ">"),
+            "cosy matthew"
+        );
+    }
+
+    #[test]
+    fn unprefix_works() {
+        assert_eq!(unprefix_non_whitespace("**", "**"), "");
+        assert_eq!(unprefix_non_whitespace("**  \n ", "**"), "  \n ");
+        assert_eq!(unprefix_non_whitespace("**emboldened", "**"), "emboldened");
+        assert_eq!(
+            unprefix_non_whitespace("\n \n**emboldened \nmatthew", "**"),
+            "\n \nemboldened \nmatthew"
+        );
+        // prefix absent: left untouched
+        assert_eq!(unprefix_non_whitespace("emboldened", "**"), "emboldened");
+    }
+
+    #[test]
+    fn unsuffix_works() {
+        assert_eq!(unsuffix_non_whitespace("**", "**"), "");
+        assert_eq!(unsuffix_non_whitespace("  \n **", "**"), "  \n ");
+        assert_eq!(unsuffix_non_whitespace("emboldened**", "**"), "emboldened");
+        assert_eq!(
+            unsuffix_non_whitespace("emboldened \nmatthew**\n \n", "**"),
+            "emboldened \nmatthew\n \n"
+        );
+        // suffix absent: left untouched
+        assert_eq!(unsuffix_non_whitespace("emboldened", "**"), "emboldened");
+    }
 }